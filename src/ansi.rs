@@ -0,0 +1,132 @@
+//! `to_ansi_256_escape_code`/`to_ansi_16_escape_code` produce the actual terminal escape
+//! sequences; no CLI subcommand writes them to stdout.
+
+use crate::Color;
+
+/// The RGB values of the 16 standard terminal colors (as used by most terminal emulators'
+/// default color schemes), in ANSI color-code order (0-15).
+const ANSI_16_COLORS: [(u8, u8, u8); 16] = [
+    (0x00, 0x00, 0x00), // black
+    (0x80, 0x00, 0x00), // red
+    (0x00, 0x80, 0x00), // green
+    (0x80, 0x80, 0x00), // yellow
+    (0x00, 0x00, 0x80), // blue
+    (0x80, 0x00, 0x80), // magenta
+    (0x00, 0x80, 0x80), // cyan
+    (0xc0, 0xc0, 0xc0), // white
+    (0x80, 0x80, 0x80), // bright black
+    (0xff, 0x00, 0x00), // bright red
+    (0x00, 0xff, 0x00), // bright green
+    (0xff, 0xff, 0x00), // bright yellow
+    (0x00, 0x00, 0xff), // bright blue
+    (0xff, 0x00, 0xff), // bright magenta
+    (0x00, 0xff, 0xff), // bright cyan
+    (0xff, 0xff, 0xff), // bright white
+];
+
+/// The six component values used by the 6x6x6 color cube of the xterm 256-color palette.
+const CUBE_STEPS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// Build the full 256-entry xterm reference palette: the 16 standard colors, followed by the
+/// 6x6x6 RGB cube, followed by a 24-step grayscale ramp.
+fn ansi_256_palette() -> Vec<(u8, u8, u8)> {
+    let mut palette = Vec::with_capacity(256);
+
+    palette.extend_from_slice(&ANSI_16_COLORS);
+
+    for r in &CUBE_STEPS {
+        for g in &CUBE_STEPS {
+            for b in &CUBE_STEPS {
+                palette.push((*r, *g, *b));
+            }
+        }
+    }
+
+    for i in 0..24 {
+        let gray = 8 + i * 10;
+        palette.push((gray, gray, gray));
+    }
+
+    palette
+}
+
+/// Find the index of the palette entry that is perceptually closest (by CIEDE2000 distance) to
+/// `color`.
+fn closest_index(color: &Color, palette: &[(u8, u8, u8)]) -> u8 {
+    palette
+        .iter()
+        .enumerate()
+        .map(|(index, &(r, g, b))| {
+            let distance = color.distance_delta_e_ciede2000(&Color::from_rgb(r, g, b));
+            (index, distance)
+        })
+        .min_by(|(_, d1), (_, d2)| d1.partial_cmp(d2).expect("distance is never NaN"))
+        .map(|(index, _)| index as u8)
+        .expect("palette is never empty")
+}
+
+impl Color {
+    /// Find the closest matching color in the xterm 256-color palette (the 16 standard colors,
+    /// the 6x6x6 RGB cube and the 24-step grayscale ramp), using the perceptual CIEDE2000
+    /// distance. Returns the corresponding palette index (0-255).
+    pub fn to_ansi_256(&self) -> u8 {
+        closest_index(self, &ansi_256_palette())
+    }
+
+    /// Find the closest matching color among the 16 standard terminal colors, using the
+    /// perceptual CIEDE2000 distance. Returns the corresponding ANSI color code (0-15).
+    pub fn to_ansi_16(&self) -> u8 {
+        closest_index(self, &ANSI_16_COLORS)
+    }
+
+    /// The terminal-ready ANSI SGR escape sequence that sets the foreground color to the
+    /// closest match in the xterm 256-color palette (see `to_ansi_256`).
+    pub fn to_ansi_256_escape_code(&self) -> String {
+        format!("\x1b[38;5;{}m", self.to_ansi_256())
+    }
+
+    /// The terminal-ready ANSI SGR escape sequence that sets the foreground color to the
+    /// closest match among the 16 standard terminal colors (see `to_ansi_16`).
+    pub fn to_ansi_16_escape_code(&self) -> String {
+        let code = self.to_ansi_16();
+        if code < 8 {
+            format!("\x1b[{}m", 30 + code)
+        } else {
+            format!("\x1b[{}m", 90 + (code - 8))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ansi_16_primaries() {
+        assert_eq!(0, Color::black().to_ansi_16());
+        assert_eq!(15, Color::white().to_ansi_16());
+        assert_eq!(9, Color::from_rgb(255, 0, 0).to_ansi_16());
+    }
+
+    #[test]
+    fn test_ansi_256_exact_matches() {
+        // The first 16 entries of the palette are the standard colors themselves, so an exact
+        // match there is returned ahead of the (also exact) entry in the 6x6x6 cube.
+        assert_eq!(0, Color::black().to_ansi_256());
+        assert_eq!(15, Color::from_rgb(255, 255, 255).to_ansi_256());
+    }
+
+    #[test]
+    fn test_ansi_256_escape_code() {
+        assert_eq!("\x1b[38;5;0m", Color::black().to_ansi_256_escape_code());
+    }
+
+    #[test]
+    fn test_ansi_16_escape_code() {
+        assert_eq!("\x1b[30m", Color::black().to_ansi_16_escape_code());
+        assert_eq!(
+            "\x1b[97m",
+            Color::from_rgb(255, 255, 255).to_ansi_16_escape_code()
+        );
+    }
+}
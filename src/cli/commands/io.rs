@@ -14,9 +14,41 @@ pub fn number_arg(matches: &ArgMatches, name: &str) -> Result<f64> {
         .map_err(|_| PastelError::CouldNotParseNumber(value_str.into()))
 }
 
+/// Split a line of input into its individual color tokens, on whitespace and commas outside of
+/// parentheses (so `rgb(0, 0, 0)` stays one token). Empty tokens (e.g. from repeated
+/// separators) are dropped. A line is treated as a comment (and
+/// yields no tokens) only if it is just `#`, or starts with `#` followed by whitespace — not if
+/// it merely starts with a hex color like `#ff0000`.
+fn color_tokens(line: &str) -> Vec<&str> {
+    let line = line.trim();
+    let is_comment = line == "#" || line.strip_prefix('#').map_or(false, |rest| {
+        rest.starts_with(|c: char| c.is_whitespace())
+    });
+    if is_comment {
+        return Vec::new();
+    }
+
+    let mut tokens = Vec::new();
+    let (mut depth, mut start) = (0usize, 0);
+    for (i, c) in line.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            _ if depth == 0 && (c.is_whitespace() || c == ',') => {
+                tokens.push(&line[start..i]);
+                start = i + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    tokens.push(&line[start..]);
+    tokens.retain(|token| !token.is_empty());
+    tokens
+}
+
 pub enum ColorArgIterator<'a> {
     FromPositionalArguments(Values<'a>),
-    FromStdin,
+    FromStdin { pending: Vec<Color> },
 }
 
 impl<'a> ColorArgIterator<'a> {
@@ -28,27 +60,60 @@ impl<'a> ColorArgIterator<'a> {
                 if atty::is(Stream::Stdin) {
                     return Err(PastelError::ColorArgRequired);
                 }
-                Ok(ColorArgIterator::FromStdin)
+                Ok(ColorArgIterator::FromStdin { pending: Vec::new() })
             }
         }
     }
 
-    pub fn color_from_stdin() -> Result<Color> {
+    /// Read lines from `reader` until one yields at least one color token (skipping blank and
+    /// `#`-comment lines), and parse all of its tokens into `Color`s.
+    fn colors_from_next_line<R: BufRead>(reader: &mut R) -> Result<Vec<Color>> {
+        loop {
+            let mut line = String::new();
+            let size = reader
+                .read_line(&mut line)
+                .map_err(|_| PastelError::ColorInvalidUTF8)?;
+
+            if size == 0 {
+                return Err(PastelError::CouldNotReadFromStdin);
+            }
+
+            let tokens = color_tokens(&line);
+            if tokens.is_empty() {
+                continue;
+            }
+
+            return tokens
+                .into_iter()
+                .map(|token| parse_color(token).ok_or(PastelError::ColorParseError(token.into())))
+                .collect();
+        }
+    }
+
+    fn colors_from_next_stdin_line() -> Result<Vec<Color>> {
         let stdin = io::stdin();
         let mut lock = stdin.lock();
+        Self::colors_from_next_line(&mut lock)
+    }
 
-        let mut line = String::new();
-        let size = lock
-            .read_line(&mut line)
-            .map_err(|_| PastelError::ColorInvalidUTF8)?;
-
-        if size == 0 {
-            return Err(PastelError::CouldNotReadFromStdin);
+    /// Read a single color from `reader`. Unlike `colors_from_next_line`, this rejects a line
+    /// that tokenizes into more than one color instead of silently discarding the extras — the
+    /// caller only has room for one `Color`.
+    fn color_from_line<R: BufRead>(reader: &mut R) -> Result<Color> {
+        let colors = Self::colors_from_next_line(reader)?;
+        if colors.len() > 1 {
+            return Err(PastelError::ColorParseError(
+                "expected a single color on this line, but found multiple".into(),
+            ));
         }
 
-        let line = line.trim();
+        Ok(colors[0].clone())
+    }
 
-        parse_color(&line).ok_or(PastelError::ColorParseError(line.to_string()))
+    pub fn color_from_stdin() -> Result<Color> {
+        let stdin = io::stdin();
+        let mut lock = stdin.lock();
+        Self::color_from_line(&mut lock)
     }
 
     pub fn from_color_arg(arg: &str) -> Result<Color> {
@@ -70,11 +135,106 @@ impl<'a> Iterator for ColorArgIterator<'a> {
                 Some(color_arg) => Some(Self::from_color_arg(color_arg)),
                 None => None,
             },
-            ColorArgIterator::FromStdin => match Self::color_from_stdin() {
-                Ok(color) => Some(Ok(color)),
-                Err(PastelError::CouldNotReadFromStdin) => None,
-                err @ Err(_) => Some(err),
-            },
+            ColorArgIterator::FromStdin { ref mut pending } => {
+                if pending.is_empty() {
+                    match Self::colors_from_next_stdin_line() {
+                        Ok(colors) => *pending = colors,
+                        Err(PastelError::CouldNotReadFromStdin) => return None,
+                        err @ Err(_) => return Some(err),
+                    }
+                }
+
+                // `pending` was just refilled with at least one color, unless stdin produced a
+                // parse error above (in which case we already returned).
+                Some(Ok(pending.remove(0)))
+            }
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_color_tokens_splits_on_whitespace_and_commas() {
+        assert_eq!(
+            vec!["#ff0", "#0ff", "rgb(0,0,0)"],
+            color_tokens("#ff0 #0ff, rgb(0,0,0)\n")
+        );
+    }
+
+    #[test]
+    fn test_color_tokens_drops_empty_tokens() {
+        assert_eq!(vec!["red", "blue"], color_tokens("  red,  , blue  \n"));
+    }
+
+    #[test]
+    fn test_color_tokens_hex_leading_line_is_not_a_comment() {
+        assert_eq!(vec!["#ff0000"], color_tokens("#ff0000\n"));
+        assert_eq!(vec!["#ff0000", "#00ff00"], color_tokens("#ff0000,#00ff00\n"));
+    }
+
+    #[test]
+    fn test_color_tokens_comment_lines_are_skipped() {
+        assert!(color_tokens("# this is a comment\n").is_empty());
+        assert!(color_tokens("#\n").is_empty());
+        assert!(color_tokens("#\tcomment\n").is_empty());
+    }
+
+    #[test]
+    fn test_color_tokens_blank_line() {
+        assert!(color_tokens("\n").is_empty());
+    }
+
+    #[test]
+    fn test_colors_from_next_line_reads_multiple_colors_per_line() {
+        let mut reader = Cursor::new(b"#ff0000 #00ff00, #0000ff\n".to_vec());
+        let colors = ColorArgIterator::colors_from_next_line(&mut reader).unwrap();
+
+        assert_eq!(
+            vec![
+                Color::from_rgb(255, 0, 0),
+                Color::from_rgb(0, 255, 0),
+                Color::from_rgb(0, 0, 255),
+            ],
+            colors
+        );
+    }
+
+    #[test]
+    fn test_colors_from_next_line_skips_comments_and_blank_lines() {
+        let mut reader = Cursor::new(b"# a comment\n\n#ff0000\n".to_vec());
+        let colors = ColorArgIterator::colors_from_next_line(&mut reader).unwrap();
+
+        assert_eq!(vec![Color::from_rgb(255, 0, 0)], colors);
+    }
+
+    #[test]
+    fn test_colors_from_next_line_ends_with_no_colors() {
+        let mut reader = Cursor::new(b"# only comments\n".to_vec());
+        assert!(matches!(
+            ColorArgIterator::colors_from_next_line(&mut reader),
+            Err(PastelError::CouldNotReadFromStdin)
+        ));
+    }
+
+    #[test]
+    fn test_color_from_line_single_color() {
+        let mut reader = Cursor::new(b"#ff0000\n".to_vec());
+        assert_eq!(
+            Color::from_rgb(255, 0, 0),
+            ColorArgIterator::color_from_line(&mut reader).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_color_from_line_rejects_multiple_colors_instead_of_dropping_them() {
+        let mut reader = Cursor::new(b"#ff0 #0ff, rgb(0,0,0)\n".to_vec());
+        assert!(matches!(
+            ColorArgIterator::color_from_line(&mut reader),
+            Err(PastelError::ColorParseError(_))
+        ));
+    }
+}
@@ -0,0 +1,99 @@
+use crate::helper::clamp;
+use crate::types::Scalar;
+use crate::Color;
+
+/// A color in the CMYK (cyan, magenta, yellow, key/black) color model, as used in print design.
+/// All components are numbers between 0.0 and 1.0.
+///
+/// CMYK is available through `Color::from_cmyk` and `Color::to_cmyk` only; the command line
+/// color parser does not accept a `cmyk(...)` syntax and there is no CMYK output format.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CMYK {
+    pub c: Scalar,
+    pub m: Scalar,
+    pub y: Scalar,
+    pub k: Scalar,
+}
+
+impl Color {
+    /// Create a `Color` from CMYK values between 0.0 and 1.0. Values outside this range will be
+    /// clamped.
+    pub fn from_cmyk(c: Scalar, m: Scalar, y: Scalar, k: Scalar) -> Color {
+        let c = clamp(0.0, 1.0, c);
+        let m = clamp(0.0, 1.0, m);
+        let y = clamp(0.0, 1.0, y);
+        let k = clamp(0.0, 1.0, k);
+
+        let r = (1.0 - c) * (1.0 - k);
+        let g = (1.0 - m) * (1.0 - k);
+        let b = (1.0 - y) * (1.0 - k);
+
+        Color::from_rgb_scaled(r, g, b)
+    }
+
+    /// Convert a `Color` to its cyan, magenta, yellow and key (black) values. All numbers are
+    /// between 0.0 and 1.0.
+    pub fn to_cmyk(&self) -> CMYK {
+        let c = self.to_rgba_scaled();
+
+        let k = 1.0 - Scalar::max(c.r, Scalar::max(c.g, c.b));
+
+        if k == 1.0 {
+            return CMYK {
+                c: 0.0,
+                m: 0.0,
+                y: 0.0,
+                k: 1.0,
+            };
+        }
+
+        CMYK {
+            c: (1.0 - c.r - k) / (1.0 - k),
+            m: (1.0 - c.g - k) / (1.0 - k),
+            y: (1.0 - c.b - k) / (1.0 - k),
+            k,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cmyk_primaries() {
+        assert_eq!(Color::black(), Color::from_cmyk(0.0, 0.0, 0.0, 1.0));
+        assert_eq!(Color::white(), Color::from_cmyk(0.0, 0.0, 0.0, 0.0));
+        assert_eq!(Color::from_rgb(255, 0, 0), Color::from_cmyk(0.0, 1.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn test_cmyk_roundtrip() {
+        let roundtrip = |r, g, b| {
+            let color1 = Color::from_rgb(r, g, b);
+            let cmyk = color1.to_cmyk();
+            let color2 = Color::from_cmyk(cmyk.c, cmyk.m, cmyk.y, cmyk.k);
+            assert_eq!(color1, color2);
+        };
+
+        roundtrip(0, 0, 0);
+        roundtrip(255, 255, 255);
+        roundtrip(255, 0, 0);
+        roundtrip(0, 255, 0);
+        roundtrip(0, 0, 255);
+        roundtrip(12, 34, 56);
+    }
+
+    #[test]
+    fn test_cmyk_black_is_all_zero_except_key() {
+        assert_eq!(
+            CMYK {
+                c: 0.0,
+                m: 0.0,
+                y: 0.0,
+                k: 1.0
+            },
+            Color::black().to_cmyk()
+        );
+    }
+}
@@ -0,0 +1,182 @@
+use crate::helper::clamp;
+use crate::types::Scalar;
+use crate::Color;
+
+/// D65 reference white point, used for the XYZ <-> Lab conversions below.
+const WHITE_X: Scalar = 0.95047;
+const WHITE_Y: Scalar = 1.0;
+const WHITE_Z: Scalar = 1.08883;
+
+/// A point in the CIE 1931 XYZ color space, relative to the D65 white point.
+#[derive(Debug, Clone, PartialEq)]
+pub struct XYZ {
+    pub x: Scalar,
+    pub y: Scalar,
+    pub z: Scalar,
+    pub alpha: Scalar,
+}
+
+/// A point in the CIE L*a*b* color space. Unlike `RGBA`, values here are not
+/// clamped to a particular gamut, which makes `Lab` a good space to perform
+/// perceptual operations (interpolation, lightness adjustments, ...) in
+/// before converting back to sRGB.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lab {
+    pub l: Scalar,
+    pub a: Scalar,
+    pub b: Scalar,
+    pub alpha: Scalar,
+}
+
+/// Convert a single gamma-corrected sRGB channel (in [0, 1]) to linear RGB.
+fn srgb_to_linear(c: Scalar) -> Scalar {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        Scalar::powf((c + 0.055) / 1.055, 2.4)
+    }
+}
+
+/// Convert a single linear RGB channel (in [0, 1]) to gamma-corrected sRGB.
+fn linear_to_srgb(c: Scalar) -> Scalar {
+    if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * Scalar::powf(c, 1.0 / 2.4) - 0.055
+    }
+}
+
+/// The `f` function from the CIE Lab definition, applied to a channel that
+/// has already been divided by its corresponding white point component.
+fn lab_f(t: Scalar) -> Scalar {
+    const DELTA: Scalar = 6.0 / 29.0;
+    if t > DELTA * DELTA * DELTA {
+        Scalar::powf(t, 1.0 / 3.0)
+    } else {
+        t / (3.0 * DELTA * DELTA) + 4.0 / 29.0
+    }
+}
+
+/// The inverse of `lab_f`.
+fn lab_f_inv(t: Scalar) -> Scalar {
+    const DELTA: Scalar = 6.0 / 29.0;
+    if t > DELTA {
+        t * t * t
+    } else {
+        3.0 * DELTA * DELTA * (t - 4.0 / 29.0)
+    }
+}
+
+impl Color {
+    /// Convert a `Color` to its coordinates in the CIE XYZ color space (relative to the D65
+    /// white point). This is mainly used as an intermediate step to/from `Lab`.
+    pub fn to_xyz(&self) -> XYZ {
+        let c = self.to_rgba_scaled();
+
+        let r = srgb_to_linear(c.r);
+        let g = srgb_to_linear(c.g);
+        let b = srgb_to_linear(c.b);
+
+        XYZ {
+            x: 0.4124 * r + 0.3576 * g + 0.1805 * b,
+            y: 0.2126 * r + 0.7152 * g + 0.0722 * b,
+            z: 0.0193 * r + 0.1192 * g + 0.9505 * b,
+            alpha: self.alpha,
+        }
+    }
+
+    /// Create a `Color` from CIE XYZ coordinates (relative to the D65 white point). Values
+    /// outside of the sRGB gamut are clamped back into range.
+    pub fn from_xyz(x: Scalar, y: Scalar, z: Scalar, alpha: Scalar) -> Color {
+        let r = 3.2406 * x - 1.5372 * y - 0.4986 * z;
+        let g = -0.9689 * x + 1.8758 * y + 0.0415 * z;
+        let b = 0.0557 * x - 0.2040 * y + 1.0570 * z;
+
+        Color::from_rgba_scaled(
+            clamp(0.0, 1.0, linear_to_srgb(r)),
+            clamp(0.0, 1.0, linear_to_srgb(g)),
+            clamp(0.0, 1.0, linear_to_srgb(b)),
+            alpha,
+        )
+    }
+
+    /// Convert a `Color` to its coordinates in the CIE L*a*b* color space.
+    pub fn to_lab(&self) -> Lab {
+        let c = self.to_xyz();
+
+        let fx = lab_f(c.x / WHITE_X);
+        let fy = lab_f(c.y / WHITE_Y);
+        let fz = lab_f(c.z / WHITE_Z);
+
+        Lab {
+            l: 116.0 * fy - 16.0,
+            a: 500.0 * (fx - fy),
+            b: 200.0 * (fy - fz),
+            alpha: c.alpha,
+        }
+    }
+
+    /// Create a `Color` from CIE L*a*b* coordinates. Note that not every point in `Lab` space
+    /// corresponds to a color inside the sRGB gamut; the result is clamped back into range by
+    /// `from_xyz`.
+    pub fn from_lab(l: Scalar, a: Scalar, b: Scalar, alpha: Scalar) -> Color {
+        let fy = (l + 16.0) / 116.0;
+        let fx = fy + a / 500.0;
+        let fz = fy - b / 200.0;
+
+        Color::from_xyz(
+            WHITE_X * lab_f_inv(fx),
+            WHITE_Y * lab_f_inv(fy),
+            WHITE_Z * lab_f_inv(fz),
+            alpha,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_xyz_roundtrip() {
+        let roundtrip = |r, g, b| {
+            let color1 = Color::from_rgb(r, g, b);
+            let xyz = color1.to_xyz();
+            let color2 = Color::from_xyz(xyz.x, xyz.y, xyz.z, xyz.alpha);
+            assert_eq!(color1, color2);
+        };
+
+        roundtrip(0, 0, 0);
+        roundtrip(255, 255, 255);
+        roundtrip(255, 0, 0);
+        roundtrip(0, 255, 0);
+        roundtrip(0, 0, 255);
+        roundtrip(12, 34, 56);
+    }
+
+    #[test]
+    fn test_lab_roundtrip() {
+        let roundtrip = |r, g, b| {
+            let color1 = Color::from_rgb(r, g, b);
+            let lab = color1.to_lab();
+            let color2 = Color::from_lab(lab.l, lab.a, lab.b, lab.alpha);
+            assert_eq!(color1, color2);
+        };
+
+        roundtrip(0, 0, 0);
+        roundtrip(255, 255, 255);
+        roundtrip(255, 0, 0);
+        roundtrip(0, 255, 0);
+        roundtrip(0, 0, 255);
+        roundtrip(12, 34, 56);
+    }
+
+    #[test]
+    fn test_lab_of_white() {
+        let lab = Color::white().to_lab();
+        assert_relative_eq!(lab.l, 100.0, epsilon = 1e-1);
+        assert_relative_eq!(lab.a, 0.0, epsilon = 1e-1);
+        assert_relative_eq!(lab.b, 0.0, epsilon = 1e-1);
+    }
+}
@@ -0,0 +1,139 @@
+use crate::helper::mod_positive;
+use crate::types::Scalar;
+use crate::Color;
+
+impl Color {
+    /// The Euclidean distance between two colors in CIE L*a*b* space ("CIE76"). This is a
+    /// simple, but not very accurate measure of color difference. Prefer
+    /// `distance_delta_e_ciede2000` unless you need the cheaper computation.
+    pub fn distance_delta_e_cie76(&self, other: &Color) -> Scalar {
+        let l1 = self.to_lab();
+        let l2 = other.to_lab();
+
+        Scalar::sqrt(
+            (l1.l - l2.l).powi(2) + (l1.a - l2.a).powi(2) + (l1.b - l2.b).powi(2),
+        )
+    }
+
+    /// The CIEDE2000 color difference between two colors, a perceptually uniform measure of how
+    /// similar two colors appear to the human eye. A value close to 0 means the colors are
+    /// (almost) indistinguishable, while larger values indicate a more noticeable difference.
+    ///
+    /// Reference: Sharma, G., Wu, W., Dalal, E. N. (2005). The CIEDE2000 color-difference
+    /// formula: Implementation notes, supplementary test data, and mathematical observations.
+    pub fn distance_delta_e_ciede2000(&self, other: &Color) -> Scalar {
+        let lab1 = self.to_lab();
+        let lab2 = other.to_lab();
+
+        let c1 = Scalar::sqrt(lab1.a * lab1.a + lab1.b * lab1.b);
+        let c2 = Scalar::sqrt(lab2.a * lab2.a + lab2.b * lab2.b);
+        let c_bar = (c1 + c2) / 2.0;
+
+        let c_bar7 = c_bar.powi(7);
+        let g = 0.5 * (1.0 - Scalar::sqrt(c_bar7 / (c_bar7 + 25.0f64.powi(7))));
+
+        let a1_p = (1.0 + g) * lab1.a;
+        let a2_p = (1.0 + g) * lab2.a;
+
+        let c1_p = Scalar::sqrt(a1_p * a1_p + lab1.b * lab1.b);
+        let c2_p = Scalar::sqrt(a2_p * a2_p + lab2.b * lab2.b);
+
+        let h1_p = if c1_p == 0.0 {
+            0.0
+        } else {
+            mod_positive(Scalar::atan2(lab1.b, a1_p).to_degrees(), 360.0)
+        };
+        let h2_p = if c2_p == 0.0 {
+            0.0
+        } else {
+            mod_positive(Scalar::atan2(lab2.b, a2_p).to_degrees(), 360.0)
+        };
+
+        let delta_l_p = lab2.l - lab1.l;
+        let delta_c_p = c2_p - c1_p;
+
+        let delta_h_p = if c1_p * c2_p == 0.0 {
+            0.0
+        } else {
+            let diff = h2_p - h1_p;
+            if diff > 180.0 {
+                diff - 360.0
+            } else if diff < -180.0 {
+                diff + 360.0
+            } else {
+                diff
+            }
+        };
+        let delta_big_h_p = 2.0 * Scalar::sqrt(c1_p * c2_p) * (delta_h_p.to_radians() / 2.0).sin();
+
+        let l_bar_p = (lab1.l + lab2.l) / 2.0;
+        let c_bar_p = (c1_p + c2_p) / 2.0;
+
+        let h_bar_p = if c1_p * c2_p == 0.0 {
+            h1_p + h2_p
+        } else if Scalar::abs(h1_p - h2_p) <= 180.0 {
+            (h1_p + h2_p) / 2.0
+        } else if h1_p + h2_p < 360.0 {
+            (h1_p + h2_p + 360.0) / 2.0
+        } else {
+            (h1_p + h2_p - 360.0) / 2.0
+        };
+
+        let t = 1.0 - 0.17 * (h_bar_p - 30.0).to_radians().cos()
+            + 0.24 * (2.0 * h_bar_p).to_radians().cos()
+            + 0.32 * (3.0 * h_bar_p + 6.0).to_radians().cos()
+            - 0.20 * (4.0 * h_bar_p - 63.0).to_radians().cos();
+
+        let delta_theta = 30.0 * Scalar::exp(-(((h_bar_p - 275.0) / 25.0).powi(2)));
+        let c_bar_p7 = c_bar_p.powi(7);
+        let r_c = 2.0 * Scalar::sqrt(c_bar_p7 / (c_bar_p7 + 25.0f64.powi(7)));
+
+        let s_l = 1.0
+            + (0.015 * (l_bar_p - 50.0).powi(2)) / Scalar::sqrt(20.0 + (l_bar_p - 50.0).powi(2));
+        let s_c = 1.0 + 0.045 * c_bar_p;
+        let s_h = 1.0 + 0.015 * c_bar_p * t;
+
+        let r_t = -Scalar::sin((2.0 * delta_theta).to_radians()) * r_c;
+
+        Scalar::sqrt(
+            (delta_l_p / s_l).powi(2)
+                + (delta_c_p / s_c).powi(2)
+                + (delta_big_h_p / s_h).powi(2)
+                + r_t * (delta_c_p / s_c) * (delta_big_h_p / s_h),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_distance_to_self_is_zero() {
+        let c = Color::from_rgb(123, 45, 200);
+        assert_relative_eq!(c.distance_delta_e_cie76(&c), 0.0, epsilon = 1e-8);
+        assert_relative_eq!(c.distance_delta_e_ciede2000(&c), 0.0, epsilon = 1e-8);
+    }
+
+    #[test]
+    fn test_distance_is_symmetric() {
+        let c1 = Color::from_rgb(255, 0, 0);
+        let c2 = Color::from_rgb(0, 255, 0);
+
+        assert_relative_eq!(
+            c1.distance_delta_e_ciede2000(&c2),
+            c2.distance_delta_e_ciede2000(&c1),
+            epsilon = 1e-8
+        );
+    }
+
+    #[test]
+    fn test_similar_colors_are_closer_than_dissimilar_ones() {
+        let red = Color::from_rgb(255, 0, 0);
+        let almost_red = Color::from_rgb(250, 10, 10);
+        let green = Color::from_rgb(0, 255, 0);
+
+        assert!(red.distance_delta_e_ciede2000(&almost_red) < red.distance_delta_e_ciede2000(&green));
+    }
+}
@@ -0,0 +1,142 @@
+use rand::Rng;
+
+use crate::types::Scalar;
+use crate::Color;
+
+/// The number of evenly spaced values sampled per RGB channel; the candidate pool holds this
+/// number cubed (8^3 = 512 colors) before optimizing. A denser pool
+/// gives the optimizer more room to find well-separated colors, at the cost of more pairwise
+/// distance computations.
+const CANDIDATES_PER_CHANNEL: usize = 8;
+
+/// The number of annealing iterations to run while searching for a better set.
+const ITERATIONS: usize = 2000;
+
+fn candidate_pool() -> Vec<Color> {
+    let mut pool = Vec::with_capacity(CANDIDATES_PER_CHANNEL.pow(3));
+    let step = 255.0 / (CANDIDATES_PER_CHANNEL - 1) as Scalar;
+
+    for ri in 0..CANDIDATES_PER_CHANNEL {
+        for gi in 0..CANDIDATES_PER_CHANNEL {
+            for bi in 0..CANDIDATES_PER_CHANNEL {
+                let r = Scalar::round(ri as Scalar * step) as u8;
+                let g = Scalar::round(gi as Scalar * step) as u8;
+                let b = Scalar::round(bi as Scalar * step) as u8;
+                pool.push(Color::from_rgb(r, g, b));
+            }
+        }
+    }
+
+    pool
+}
+
+/// The smallest pairwise CIEDE2000 distance within `colors`.
+fn min_pairwise_distance(colors: &[Color]) -> Scalar {
+    let mut min = Scalar::INFINITY;
+    for i in 0..colors.len() {
+        for j in (i + 1)..colors.len() {
+            let d = colors[i].distance_delta_e_ciede2000(&colors[j]);
+            if d < min {
+                min = d;
+            }
+        }
+    }
+    min
+}
+
+impl Color {
+    /// Generate `n` colors that are as perceptually distinct from each other as possible. This
+    /// is useful to generate color palettes for charts or for CLI output where each series
+    /// needs to be told apart at a glance.
+    ///
+    /// The implementation samples a pool of candidates across the RGB cube and then runs a
+    /// simulated-annealing search that repeatedly swaps one chosen color for a random candidate,
+    /// keeping the swap whenever it does not decrease the minimum pairwise CIEDE2000 distance
+    /// between the chosen colors (occasionally accepting a worse swap, with a probability that
+    /// decreases over time, to escape local optima).
+    pub fn distinct_colors(n: usize) -> Vec<Color> {
+        let pool = candidate_pool();
+
+        if n == 0 {
+            return Vec::new();
+        }
+
+        let mut rng = rand::thread_rng();
+
+        let mut chosen: Vec<Color> = (0..n)
+            .map(|_| pool[rng.gen_range(0..pool.len())].clone())
+            .collect();
+
+        let mut current_min_distance = min_pairwise_distance(&chosen);
+        let mut best = chosen.clone();
+        let mut best_min_distance = current_min_distance;
+
+        for iteration in 0..ITERATIONS {
+            let temperature = 1.0 - (iteration as Scalar / ITERATIONS as Scalar);
+
+            let index = rng.gen_range(0..chosen.len());
+            let candidate = pool[rng.gen_range(0..pool.len())].clone();
+
+            let previous = chosen[index].clone();
+            chosen[index] = candidate;
+
+            let new_min_distance = min_pairwise_distance(&chosen);
+
+            let accept = new_min_distance >= current_min_distance
+                || rng.gen::<Scalar>() < temperature * 0.05;
+
+            if accept {
+                current_min_distance = new_min_distance;
+
+                if current_min_distance > best_min_distance {
+                    best_min_distance = current_min_distance;
+                    best = chosen.clone();
+                }
+            } else {
+                chosen[index] = previous;
+            }
+        }
+
+        best.sort_by(|c1, c2| {
+            c1.to_hsla()
+                .h
+                .partial_cmp(&c2.to_hsla().h)
+                .expect("hue values are never NaN")
+        });
+
+        best
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distinct_colors_count() {
+        assert_eq!(0, Color::distinct_colors(0).len());
+        assert_eq!(1, Color::distinct_colors(1).len());
+        assert_eq!(5, Color::distinct_colors(5).len());
+    }
+
+    #[test]
+    fn test_distinct_colors_improves_on_the_first_candidates() {
+        let pool = candidate_pool();
+        let first_candidates: Vec<Color> = pool.iter().take(6).cloned().collect();
+
+        let distinct = Color::distinct_colors(6);
+
+        assert!(min_pairwise_distance(&distinct) >= min_pairwise_distance(&first_candidates));
+    }
+
+    #[test]
+    fn test_distinct_colors_are_sorted_by_hue() {
+        let colors = Color::distinct_colors(5);
+        let hues: Vec<Scalar> = colors.iter().map(|c| c.to_hsla().h).collect();
+
+        let mut sorted_hues = hues.clone();
+        sorted_hues.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        assert_eq!(hues, sorted_hues);
+    }
+}
@@ -0,0 +1,88 @@
+use crate::types::Scalar;
+use crate::Color;
+
+/// A color in the HSV (hue, saturation, value; a.k.a. HSB, brightness) color model. The hue is
+/// given in degrees, as a number between 0.0 and 360.0. Saturation, value and alpha are numbers
+/// between 0.0 and 1.0.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HSV {
+    pub h: Scalar,
+    pub s: Scalar,
+    pub v: Scalar,
+    pub alpha: Scalar,
+}
+
+impl Color {
+    /// Create a `Color` from hue, saturation, value and alpha values.
+    pub fn from_hsva(hue: Scalar, saturation: Scalar, value: Scalar, alpha: Scalar) -> Color {
+        let lightness = value * (1.0 - saturation / 2.0);
+        let saturation_hsl = if lightness == 0.0 || lightness == 1.0 {
+            0.0
+        } else {
+            (value - lightness) / Scalar::min(lightness, 1.0 - lightness)
+        };
+
+        Color::from_hsla(hue, saturation_hsl, lightness, alpha)
+    }
+
+    /// Create a `Color` from hue, saturation and value values.
+    pub fn from_hsv(hue: Scalar, saturation: Scalar, value: Scalar) -> Color {
+        Self::from_hsva(hue, saturation, value, 1.0)
+    }
+
+    /// Convert a `Color` to its hue, saturation, value and alpha values.
+    pub fn to_hsva(&self) -> HSV {
+        let c = self.to_hsla();
+
+        let value = c.l + c.s * Scalar::min(c.l, 1.0 - c.l);
+        let saturation_hsv = if value == 0.0 {
+            0.0
+        } else {
+            2.0 * (1.0 - c.l / value)
+        };
+
+        HSV {
+            h: c.h,
+            s: saturation_hsv,
+            v: value,
+            alpha: c.alpha,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_hsv_of_primaries() {
+        let hsv = Color::from_rgb(255, 0, 0).to_hsva();
+        assert_relative_eq!(hsv.h, 0.0);
+        assert_relative_eq!(hsv.s, 1.0);
+        assert_relative_eq!(hsv.v, 1.0);
+    }
+
+    #[test]
+    fn test_hsv_roundtrip() {
+        let roundtrip = |h, s, v| {
+            let color1 = Color::from_hsv(h, s, v);
+            let hsv = color1.to_hsva();
+            let color2 = Color::from_hsv(hsv.h, hsv.s, hsv.v);
+            assert_eq!(color1, color2);
+        };
+
+        roundtrip(0.0, 0.0, 1.0);
+        roundtrip(0.0, 0.0, 0.5);
+        roundtrip(0.0, 0.0, 0.0);
+        roundtrip(60.0, 1.0, 0.8);
+        roundtrip(120.0, 0.5, 0.5);
+        roundtrip(240.0, 0.8, 0.3);
+    }
+
+    #[test]
+    fn test_hsv_black_and_white() {
+        assert_eq!(Color::black(), Color::from_hsv(0.0, 0.0, 0.0));
+        assert_eq!(Color::white(), Color::from_hsv(0.0, 0.0, 1.0));
+    }
+}
@@ -1,6 +1,17 @@
+mod ansi;
+mod cmyk;
+mod colorspace;
+mod delta_e;
+mod distinct;
 mod helper;
+mod hsv;
 mod types;
 
+pub use cmyk::CMYK;
+pub use hsv::HSV;
+
+pub use colorspace::{Lab, XYZ};
+
 use helper::{clamp, mod_positive};
 use types::Scalar;
 
@@ -28,8 +39,10 @@ impl Hue {
 /// The representation of a color.
 ///
 /// Note:
-/// - Colors outside the sRGB gamut (which cannot be displayed on a typical
-///   computer screen) can not be represented by `Color`.
+/// - `Color` is internally represented in HSLA, which is always inside the sRGB gamut. However,
+///   `to_lab`/`to_xyz` and their `from_*` counterparts allow working in the CIE L*a*b* / XYZ
+///   color spaces, where intermediate results may temporarily leave the sRGB gamut before being
+///   clamped back on the next conversion to RGBA.
 /// - The `PartialEq` instance compares two `Color`s by comparing their (integer)
 ///   RGB values. This is different from comparing the HSL values. For example,
 ///   HSL has many different representations of black (arbitrary hue and